@@ -44,6 +44,7 @@ pub mod support;
 #[macro_use]
 mod link;
 
+use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ptr;
 
@@ -147,6 +148,12 @@ cenum! {
         const CXCallingConv_PreserveMost = 14,
         /// Only produced by `libclang` 3.9 and later.
         const CXCallingConv_PreserveAll = 15,
+        /// Only produced by `libclang` 8.0 and later.
+        const CXCallingConv_AArch64VectorCall = 16,
+        /// Only produced by `libclang` 13.0 and later.
+        const CXCallingConv_SwiftAsync = 17,
+        /// Only produced by `libclang` 15.0 and later.
+        const CXCallingConv_AArch64SVEPCS = 18,
         const CXCallingConv_Invalid = 100,
         const CXCallingConv_Unexposed = 200,
     }
@@ -335,6 +342,16 @@ cenum! {
         const CXCursor_OMPArraySectionExpr = 147,
         /// Only produced by `libclang` 3.9 and later.
         const CXCursor_ObjCAvailabilityCheckExpr = 148,
+        /// Only produced by `libclang` 12.0 and later.
+        const CXCursor_OMPArrayShapingExpr = 150,
+        /// Only produced by `libclang` 12.0 and later.
+        const CXCursor_OMPIteratorExpr = 151,
+        /// Only produced by `libclang` 12.0 and later.
+        const CXCursor_CXXAddrspaceCastExpr = 152,
+        /// Only produced by `libclang` 14.0 and later.
+        const CXCursor_ConceptSpecializationExpr = 153,
+        /// Only produced by `libclang` 14.0 and later.
+        const CXCursor_RequiresExpr = 154,
         const CXCursor_UnexposedStmt = 200,
         const CXCursor_LabelStmt = 201,
         const CXCursor_CompoundStmt = 202,
@@ -463,6 +480,8 @@ cenum! {
         const CXCursor_TypeAliasTemplateDecl = 601,
         /// Only produced by `libclang` 3.9 and later.
         const CXCursor_StaticAssert = 602,
+        /// Only produced by `libclang` 14.0 and later.
+        const CXCursor_ConceptDecl = 604,
         /// Only produced by `libclang` 3.7 and later.
         const CXCursor_OverloadCandidate = 700,
     }
@@ -491,7 +510,7 @@ cenum! {
 cenum! {
     enum CXEvalResultKind {
         const CXEval_UnExposed = 0,
-        const CXEval_Int = 1 ,
+        const CXEval_Int = 1,
         const CXEval_Float = 2,
         const CXEval_ObjCStrLiteral = 3,
         const CXEval_StrLiteral = 4,
@@ -602,6 +621,40 @@ cenum! {
     }
 }
 
+cenum! {
+    #[cfg(feature="gte_clang_7_0")]
+    enum CXPrintingPolicyProperty {
+        const CXPrintingPolicy_Indentation = 0,
+        const CXPrintingPolicy_SuppressSpecifiers = 1,
+        const CXPrintingPolicy_SuppressTagKeyword = 2,
+        const CXPrintingPolicy_IncludeTagDefinition = 3,
+        const CXPrintingPolicy_SuppressScope = 4,
+        const CXPrintingPolicy_SuppressUnwrittenScope = 5,
+        const CXPrintingPolicy_SuppressInitializers = 6,
+        const CXPrintingPolicy_ConstantArraySizeAsWritten = 7,
+        const CXPrintingPolicy_AnonymousTagLocations = 8,
+        const CXPrintingPolicy_SuppressStrongLifetime = 9,
+        const CXPrintingPolicy_SuppressLifetimeQualifiers = 10,
+        const CXPrintingPolicy_SuppressTemplateArgsInCXXConstructors = 11,
+        const CXPrintingPolicy_Bool = 12,
+        const CXPrintingPolicy_Restrict = 13,
+        const CXPrintingPolicy_Alignof = 14,
+        const CXPrintingPolicy_UnderscoreAlignof = 15,
+        const CXPrintingPolicy_UseVoidForZeroParams = 16,
+        const CXPrintingPolicy_TerseOutput = 17,
+        const CXPrintingPolicy_PolishForDeclaration = 18,
+        const CXPrintingPolicy_Half = 19,
+        const CXPrintingPolicy_MSWChar = 20,
+        const CXPrintingPolicy_IncludeNewlines = 21,
+        const CXPrintingPolicy_MSVCFormatting = 22,
+        const CXPrintingPolicy_ConstantsAsWritten = 23,
+        const CXPrintingPolicy_SuppressImplicitBase = 24,
+        const CXPrintingPolicy_FullyQualifiedName = 25,
+        /// Duplicate of `CXPrintingPolicy_FullyQualifiedName`.
+        const CXPrintingPolicy_LastProperty = 25,
+    }
+}
+
 cenum! {
     enum CXRefQualifierKind {
         const CXRefQualifier_None = 0,
@@ -728,6 +781,10 @@ cenum! {
         const CXType_Auto = 118,
         /// Only produced by `libclang` 3.9 and later.
         const CXType_Elaborated = 119,
+        /// Only produced by `libclang` 11.0 and later.
+        const CXType_Atomic = 177,
+        /// Only produced by `libclang` 15.0 and later.
+        const CXType_BTFTagAttributed = 178,
     }
 }
 
@@ -791,6 +848,10 @@ bitflags! {
         const CXCodeComplete_IncludeMacros = 1,
         const CXCodeComplete_IncludeCodePatterns = 2,
         const CXCodeComplete_IncludeBriefComments = 4,
+        #[cfg(feature="gte_clang_6_0")]
+        const CXCodeComplete_SkipPreamble = 8,
+        #[cfg(feature="gte_clang_7_0")]
+        const CXCodeComplete_IncludeCompletionsWithFixIts = 16,
     }
 }
 
@@ -820,6 +881,10 @@ bitflags! {
         const CXCompletionContext_ObjCSelectorName = 524288,
         const CXCompletionContext_MacroName = 1048576,
         const CXCompletionContext_NaturalLanguage = 2097152,
+        /// Only produced by `libclang` 8.0 and later.
+        const CXCompletionContext_IncludedFile = 4194304,
+        /// Does not include `CXCompletionContext_IncludedFile`, unlike the value reported by
+        /// `libclang` 8.0 and later.
         const CXCompletionContext_Unknown = 4194303,
     }
 }
@@ -922,6 +987,23 @@ bitflags! {
     }
 }
 
+#[cfg(feature="gte_clang_7_0")]
+bitflags! {
+    #[repr(C)]
+    pub flags CXSymbolRole: c_uint {
+        const CXSymbolRole_None = 0,
+        const CXSymbolRole_Declaration = 1,
+        const CXSymbolRole_Definition = 2,
+        const CXSymbolRole_Reference = 4,
+        const CXSymbolRole_Read = 8,
+        const CXSymbolRole_Write = 16,
+        const CXSymbolRole_Call = 32,
+        const CXSymbolRole_Dynamic = 64,
+        const CXSymbolRole_AddressOf = 128,
+        const CXSymbolRole_Implicit = 256,
+    }
+}
+
 bitflags! {
     #[repr(C)]
     pub flags CXTranslationUnit_Flags: c_uint {
@@ -986,8 +1068,14 @@ opaque!(CXIdxClientFile);
 opaque!(CXIndex);
 opaque!(CXIndexAction);
 opaque!(CXModule);
+opaque!(CXModuleMapDescriptor);
+#[cfg(feature="gte_clang_7_0")]
+opaque!(CXPrintingPolicy);
 opaque!(CXRemapping);
+#[cfg(feature="gte_clang_5_0")]
+opaque!(CXTargetInfo);
 opaque!(CXTranslationUnit);
+opaque!(CXVirtualFileOverlay);
 
 // Transparent ___________________________________
 
@@ -1028,11 +1116,29 @@ pub struct CXCursor {
 
 default!(CXCursor);
 
+/// Compares cursors with `clang_equalCursors`, which requires `libclang` to be loaded on the
+/// current thread when the `runtime` feature is enabled.
+impl PartialEq for CXCursor {
+    fn eq(&self, other: &CXCursor) -> bool {
+        unsafe { clang_equalCursors(*self, *other) != 0 }
+    }
+}
+
+impl Eq for CXCursor {}
+
+/// Hashes cursors with `clang_hashCursor`, which requires `libclang` to be loaded on the current
+/// thread when the `runtime` feature is enabled.
+impl Hash for CXCursor {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        unsafe { clang_hashCursor(*self) }.hash(hasher)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct CXCursorAndRangeVisitor {
     pub context: *mut c_void,
-    pub visit: extern fn(*mut c_void, CXCursor, CXSourceRange) -> CXVisitorResult,
+    pub visit: Option<extern fn(*mut c_void, CXCursor, CXSourceRange) -> CXVisitorResult>,
 }
 
 default!(CXCursorAndRangeVisitor);
@@ -1127,6 +1233,8 @@ pub struct CXIdxEntityRefInfo {
     pub referencedEntity: *const CXIdxEntityInfo,
     pub parentEntity: *const CXIdxEntityInfo,
     pub container: *const CXIdxContainerInfo,
+    #[cfg(feature="gte_clang_7_0")]
+    pub role: CXSymbolRole,
 }
 
 default!(CXIdxEntityRefInfo);
@@ -1257,6 +1365,16 @@ pub struct CXSourceLocation {
 
 default!(CXSourceLocation);
 
+/// Compares locations with `clang_equalLocations`, which requires `libclang` to be loaded on the
+/// current thread when the `runtime` feature is enabled.
+impl PartialEq for CXSourceLocation {
+    fn eq(&self, other: &CXSourceLocation) -> bool {
+        unsafe { clang_equalLocations(*self, *other) != 0 }
+    }
+}
+
+impl Eq for CXSourceLocation {}
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct CXSourceRange {
@@ -1267,6 +1385,16 @@ pub struct CXSourceRange {
 
 default!(CXSourceRange);
 
+/// Compares ranges with `clang_equalRanges`, which requires `libclang` to be loaded on the
+/// current thread when the `runtime` feature is enabled.
+impl PartialEq for CXSourceRange {
+    fn eq(&self, other: &CXSourceRange) -> bool {
+        unsafe { clang_equalRanges(*self, *other) != 0 }
+    }
+}
+
+impl Eq for CXSourceRange {}
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct CXSourceRangeList {
@@ -1332,6 +1460,16 @@ pub struct CXType {
 
 default!(CXType);
 
+/// Compares types with `clang_equalTypes`, which requires `libclang` to be loaded on the current
+/// thread when the `runtime` feature is enabled.
+impl PartialEq for CXType {
+    fn eq(&self, other: &CXType) -> bool {
+        unsafe { clang_equalTypes(*self, *other) != 0 }
+    }
+}
+
+impl Eq for CXType {}
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct CXUnsavedFile {
@@ -1355,14 +1493,14 @@ default!(CXVersion);
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct IndexerCallbacks {
-    pub abortQuery: extern fn(CXClientData, *mut c_void) -> c_int,
-    pub diagnostic: extern fn(CXClientData, CXDiagnosticSet, *mut c_void),
-    pub enteredMainFile: extern fn(CXClientData, CXFile, *mut c_void) -> CXIdxClientFile,
-    pub ppIncludedFile: extern fn(CXClientData, *const CXIdxIncludedFileInfo) -> CXIdxClientFile,
-    pub importedASTFile: extern fn(CXClientData, *const CXIdxImportedASTFileInfo) -> CXIdxClientASTFile,
-    pub startedTranslationUnit: extern fn(CXClientData, *mut c_void) -> CXIdxClientContainer,
-    pub indexDeclaration: extern fn(CXClientData, *const CXIdxDeclInfo),
-    pub indexEntityReference: extern fn(CXClientData, *const CXIdxEntityRefInfo),
+    pub abortQuery: Option<extern fn(CXClientData, *mut c_void) -> c_int>,
+    pub diagnostic: Option<extern fn(CXClientData, CXDiagnosticSet, *mut c_void)>,
+    pub enteredMainFile: Option<extern fn(CXClientData, CXFile, *mut c_void) -> CXIdxClientFile>,
+    pub ppIncludedFile: Option<extern fn(CXClientData, *const CXIdxIncludedFileInfo) -> CXIdxClientFile>,
+    pub importedASTFile: Option<extern fn(CXClientData, *const CXIdxImportedASTFileInfo) -> CXIdxClientASTFile>,
+    pub startedTranslationUnit: Option<extern fn(CXClientData, *mut c_void) -> CXIdxClientContainer>,
+    pub indexDeclaration: Option<extern fn(CXClientData, *const CXIdxDeclInfo)>,
+    pub indexEntityReference: Option<extern fn(CXClientData, *const CXIdxEntityRefInfo)>,
 }
 
 default!(IndexerCallbacks);
@@ -1405,9 +1543,11 @@ link! {
     #[cfg(feature="gte_clang_3_8")]
     pub fn clang_CompileCommand_getMappedSourcePath(command: CXCompileCommand, index: c_uint) -> CXString;
     pub fn clang_CompileCommand_getNumArgs(command: CXCompileCommand) -> c_uint;
-    pub fn clang_CompileCommands_dispose(command: CXCompileCommands);
-    pub fn clang_CompileCommands_getCommand(command: CXCompileCommands, index: c_uint) -> CXCompileCommand;
-    pub fn clang_CompileCommands_getSize(command: CXCompileCommands) -> c_uint;
+    #[cfg(feature="gte_clang_3_8")]
+    pub fn clang_CompileCommand_getNumMappedSources(command: CXCompileCommand) -> c_uint;
+    pub fn clang_CompileCommands_dispose(commands: CXCompileCommands);
+    pub fn clang_CompileCommands_getCommand(commands: CXCompileCommands, index: c_uint) -> CXCompileCommand;
+    pub fn clang_CompileCommands_getSize(commands: CXCompileCommands) -> c_uint;
     #[cfg(feature="gte_clang_3_9")]
     pub fn clang_Cursor_Evaluate(cursor: CXCursor) -> CXEvalResult;
     pub fn clang_Cursor_getArgument(cursor: CXCursor, index: c_uint) -> CXCursor;
@@ -1461,16 +1601,29 @@ link! {
     pub fn clang_EvalResult_getAsDouble(result: CXEvalResult) -> libc::c_double;
     #[cfg(feature="gte_clang_3_9")]
     pub fn clang_EvalResult_getAsInt(result: CXEvalResult) -> c_int;
+    #[cfg(feature="gte_clang_4_0")]
+    pub fn clang_EvalResult_getAsLongLong(result: CXEvalResult) -> c_longlong;
     #[cfg(feature="gte_clang_3_9")]
     pub fn clang_EvalResult_getAsStr(result: CXEvalResult) -> *const c_char;
+    #[cfg(feature="gte_clang_4_0")]
+    pub fn clang_EvalResult_getAsUnsigned(result: CXEvalResult) -> c_ulonglong;
     #[cfg(feature="gte_clang_3_9")]
     pub fn clang_EvalResult_getKind(result: CXEvalResult) -> CXEvalResultKind;
+    #[cfg(feature="gte_clang_4_0")]
+    pub fn clang_EvalResult_isUnsignedInt(result: CXEvalResult) -> c_uint;
     #[cfg(feature="gte_clang_3_6")]
     pub fn clang_File_isEqual(left: CXFile, right: CXFile) -> c_int;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_File_tryGetRealPathName(file: CXFile) -> CXString;
     pub fn clang_IndexAction_create(index: CXIndex) -> CXIndexAction;
-    pub fn clang_IndexAction_dispose(index: CXIndexAction);
+    pub fn clang_IndexAction_dispose(action: CXIndexAction);
     pub fn clang_Location_isFromMainFile(location: CXSourceLocation) -> c_int;
     pub fn clang_Location_isInSystemHeader(location: CXSourceLocation) -> c_int;
+    pub fn clang_ModuleMapDescriptor_create(options: c_uint) -> CXModuleMapDescriptor;
+    pub fn clang_ModuleMapDescriptor_dispose(descriptor: CXModuleMapDescriptor);
+    pub fn clang_ModuleMapDescriptor_setFrameworkModuleName(descriptor: CXModuleMapDescriptor, name: *const c_char) -> CXErrorCode;
+    pub fn clang_ModuleMapDescriptor_setUmbrellaHeader(descriptor: CXModuleMapDescriptor, name: *const c_char) -> CXErrorCode;
+    pub fn clang_ModuleMapDescriptor_writeToBuffer(descriptor: CXModuleMapDescriptor, options: c_uint, buffer: *mut *mut c_char, buffer_size: *mut c_uint) -> CXErrorCode;
     pub fn clang_Module_getASTFile(module: CXModule) -> CXFile;
     pub fn clang_Module_getFullName(module: CXModule) -> CXString;
     pub fn clang_Module_getName(module: CXModule) -> CXString;
@@ -1478,7 +1631,19 @@ link! {
     pub fn clang_Module_getParent(module: CXModule) -> CXModule;
     pub fn clang_Module_getTopLevelHeader(tu: CXTranslationUnit, module: CXModule, index: c_uint) -> CXFile;
     pub fn clang_Module_isSystem(module: CXModule) -> c_int;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_PrintingPolicy_dispose(policy: CXPrintingPolicy);
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_PrintingPolicy_getProperty(policy: CXPrintingPolicy, property: CXPrintingPolicyProperty) -> c_uint;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_PrintingPolicy_setProperty(policy: CXPrintingPolicy, property: CXPrintingPolicyProperty, value: c_uint);
     pub fn clang_Range_isNull(range: CXSourceRange) -> c_int;
+    #[cfg(feature="gte_clang_5_0")]
+    pub fn clang_TargetInfo_dispose(info: CXTargetInfo);
+    #[cfg(feature="gte_clang_5_0")]
+    pub fn clang_TargetInfo_getPointerWidth(info: CXTargetInfo) -> c_int;
+    #[cfg(feature="gte_clang_5_0")]
+    pub fn clang_TargetInfo_getTriple(info: CXTargetInfo) -> CXString;
     pub fn clang_Type_getAlignOf(type_: CXType) -> c_longlong;
     pub fn clang_Type_getCXXRefQualifier(type_: CXType) -> CXRefQualifierKind;
     pub fn clang_Type_getClassType(type_: CXType) -> CXType;
@@ -1490,8 +1655,15 @@ link! {
     pub fn clang_Type_getOffsetOf(type_: CXType, field: *const c_char) -> c_longlong;
     pub fn clang_Type_getSizeOf(type_: CXType) -> c_longlong;
     pub fn clang_Type_getTemplateArgumentAsType(type_: CXType, index: c_uint) -> CXType;
+    #[cfg(feature="gte_clang_11_0")]
+    pub fn clang_Type_getValueType(type_: CXType) -> CXType;
     #[cfg(feature="gte_clang_3_7")]
     pub fn clang_Type_visitFields(type_: CXType, visitor: CXFieldVisitor, data: CXClientData) -> CXVisitorResult;
+    pub fn clang_VirtualFileOverlay_addFileMapping(overlay: CXVirtualFileOverlay, virtual_: *const c_char, real: *const c_char) -> CXErrorCode;
+    pub fn clang_VirtualFileOverlay_create(options: c_uint) -> CXVirtualFileOverlay;
+    pub fn clang_VirtualFileOverlay_dispose(overlay: CXVirtualFileOverlay);
+    pub fn clang_VirtualFileOverlay_setCaseSensitivity(overlay: CXVirtualFileOverlay, case_sensitive: c_int) -> CXErrorCode;
+    pub fn clang_VirtualFileOverlay_writeToBuffer(overlay: CXVirtualFileOverlay, options: c_uint, buffer: *mut *mut c_char, buffer_size: *mut c_uint) -> CXErrorCode;
     pub fn clang_annotateTokens(tu: CXTranslationUnit, tokens: *mut CXToken, n_tokens: c_uint, cursors: *mut CXCursor);
     pub fn clang_codeCompleteAt(tu: CXTranslationUnit, file: *const c_char, line: c_uint, column: c_uint, unsaved: *mut CXUnsavedFile, n_unsaved: c_uint, flags: CXCodeComplete_Flags) -> *mut CXCodeCompleteResults;
     pub fn clang_codeCompleteGetContainerKind(results: *mut CXCodeCompleteResults, incomplete: *mut c_uint) -> CXCursorKind;
@@ -1536,7 +1708,7 @@ link! {
     pub fn clang_equalRanges(left: CXSourceRange, right: CXSourceRange) -> c_uint;
     pub fn clang_equalTypes(left: CXType, right: CXType) -> c_uint;
     pub fn clang_executeOnThread(function: extern fn(*mut c_void), data: *mut c_void, stack: c_uint);
-    pub fn clang_findIncludesInFile(tu: CXTranslationUnit, file: CXFile, cursor: CXCursorAndRangeVisitor) -> CXResult;
+    pub fn clang_findIncludesInFile(tu: CXTranslationUnit, file: CXFile, visitor: CXCursorAndRangeVisitor) -> CXResult;
     pub fn clang_findReferencesInFile(cursor: CXCursor, file: CXFile, visitor: CXCursorAndRangeVisitor) -> CXResult;
     pub fn clang_formatDiagnostic(diagnostic: CXDiagnostic, flags: CXDiagnosticDisplayOptions) -> CXString;
     #[cfg(feature="gte_clang_3_7")]
@@ -1557,7 +1729,11 @@ link! {
     pub fn clang_getCompletionChunkCompletionString(string: CXCompletionString, index: c_uint) -> CXCompletionString;
     pub fn clang_getCompletionChunkKind(string: CXCompletionString, index: c_uint) -> CXCompletionChunkKind;
     pub fn clang_getCompletionChunkText(string: CXCompletionString, index: c_uint) -> CXString;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_getCompletionFixIt(results: *mut CXCodeCompleteResults, completion_index: c_uint, fixit_index: c_uint, range: *mut CXSourceRange) -> CXString;
     pub fn clang_getCompletionNumAnnotations(string: CXCompletionString) -> c_uint;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_getCompletionNumFixIts(results: *mut CXCodeCompleteResults, completion_index: c_uint) -> c_uint;
     pub fn clang_getCompletionParent(string: CXCompletionString, kind: *mut CXCursorKind) -> CXString;
     pub fn clang_getCompletionPriority(string: CXCompletionString) -> c_uint;
     pub fn clang_getCursor(tu: CXTranslationUnit, location: CXSourceLocation) -> CXCursor;
@@ -1573,6 +1749,10 @@ link! {
     pub fn clang_getCursorLinkage(cursor: CXCursor) -> CXLinkageKind;
     pub fn clang_getCursorLocation(cursor: CXCursor) -> CXSourceLocation;
     pub fn clang_getCursorPlatformAvailability(cursor: CXCursor, deprecated: *mut c_int, deprecated_message: *mut CXString, unavailable: *mut c_int, unavailable_message: *mut CXString, availability: *mut CXPlatformAvailability, n_availability: c_int) -> c_int;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_getCursorPrettyPrinted(cursor: CXCursor, policy: CXPrintingPolicy) -> CXString;
+    #[cfg(feature="gte_clang_7_0")]
+    pub fn clang_getCursorPrintingPolicy(cursor: CXCursor) -> CXPrintingPolicy;
     pub fn clang_getCursorReferenceNameRange(cursor: CXCursor, flags: CXNameRefFlags, index: c_uint) -> CXSourceRange;
     pub fn clang_getCursorReferenced(cursor: CXCursor) -> CXCursor;
     pub fn clang_getCursorResultType(cursor: CXCursor) -> CXType;
@@ -1605,6 +1785,12 @@ link! {
     pub fn clang_getExpansionLocation(location: CXSourceLocation, file: *mut CXFile, line: *mut c_uint, column: *mut c_uint, offset: *mut c_uint);
     pub fn clang_getFieldDeclBitWidth(cursor: CXCursor) -> c_int;
     pub fn clang_getFile(tu: CXTranslationUnit, file: *const c_char) -> CXFile;
+    /// Returns the contents of the supplied file as seen by `libclang` or a null pointer if the
+    /// file is not loaded in the supplied translation unit.
+    ///
+    /// The returned buffer is owned by the translation unit and must not be modified or freed.
+    #[cfg(feature="gte_clang_6_0")]
+    pub fn clang_getFileContents(tu: CXTranslationUnit, file: CXFile, size: *mut libc::size_t) -> *const c_char;
     pub fn clang_getFileLocation(location: CXSourceLocation, file: *mut CXFile, line: *mut c_uint, column: *mut c_uint, offset: *mut c_uint);
     pub fn clang_getFileName(file: CXFile) -> CXString;
     pub fn clang_getFileTime(file: CXFile) -> time_t;
@@ -1647,6 +1833,8 @@ link! {
     pub fn clang_getTokenSpelling(tu: CXTranslationUnit, token: CXToken) -> CXString;
     pub fn clang_getTranslationUnitCursor(tu: CXTranslationUnit) -> CXCursor;
     pub fn clang_getTranslationUnitSpelling(tu: CXTranslationUnit) -> CXString;
+    #[cfg(feature="gte_clang_5_0")]
+    pub fn clang_getTranslationUnitTargetInfo(tu: CXTranslationUnit) -> CXTargetInfo;
     pub fn clang_getTypeDeclaration(type_: CXType) -> CXCursor;
     pub fn clang_getTypeKindSpelling(type_: CXTypeKind) -> CXString;
     pub fn clang_getTypeSpelling(type_: CXType) -> CXString;
@@ -1654,10 +1842,24 @@ link! {
     pub fn clang_hashCursor(cursor: CXCursor) -> c_uint;
     pub fn clang_indexLoc_getCXSourceLocation(location: CXIdxLoc) -> CXSourceLocation;
     pub fn clang_indexLoc_getFileLocation(location: CXIdxLoc, index_file: *mut CXIdxClientFile, file: *mut CXFile, line: *mut c_uint, column: *mut c_uint, offset: *mut c_uint);
-    pub fn clang_indexSourceFile(index: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, n_callbacks: c_uint, index_flags: CXIndexOptFlags, file: *const c_char, arguments: *const *const c_char, n_arguments: c_int, unsaved: *mut CXUnsavedFile, n_unsaved: c_uint, tu: *mut CXTranslationUnit, tu_flags: CXTranslationUnit_Flags) -> CXErrorCode;
+    /// Parses and indexes the supplied source file, invoking the supplied callbacks as entities
+    /// are encountered.
+    ///
+    /// `callbacks_size` should be `mem::size_of::<IndexerCallbacks>()` so that `libclang` knows
+    /// which callbacks are present. If `tu` is not null and the source file is parsed, the
+    /// resulting translation unit is written to it and must be disposed of by the caller with
+    /// `clang_disposeTranslationUnit`.
+    pub fn clang_indexSourceFile(action: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, callbacks_size: c_uint, index_flags: CXIndexOptFlags, file: *const c_char, arguments: *const *const c_char, n_arguments: c_int, unsaved: *mut CXUnsavedFile, n_unsaved: c_uint, tu: *mut CXTranslationUnit, tu_flags: CXTranslationUnit_Flags) -> CXErrorCode;
     #[cfg(feature="gte_clang_3_8")]
-    pub fn clang_indexSourceFileFullArgv(index: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, n_callbacks: c_uint, index_flags: CXIndexOptFlags, file: *const c_char, arguments: *const *const c_char, n_arguments: c_int, unsaved: *mut CXUnsavedFile, n_unsaved: c_uint, tu: *mut CXTranslationUnit, tu_flags: CXTranslationUnit_Flags) -> CXErrorCode;
-    pub fn clang_indexTranslationUnit(index: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, n_callbacks: c_uint, flags: CXIndexOptFlags, tu: CXTranslationUnit) -> c_int;
+    pub fn clang_indexSourceFileFullArgv(action: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, callbacks_size: c_uint, index_flags: CXIndexOptFlags, file: *const c_char, arguments: *const *const c_char, n_arguments: c_int, unsaved: *mut CXUnsavedFile, n_unsaved: c_uint, tu: *mut CXTranslationUnit, tu_flags: CXTranslationUnit_Flags) -> CXErrorCode;
+    /// Indexes the supplied translation unit, invoking the supplied callbacks as entities are
+    /// encountered.
+    ///
+    /// Unlike `clang_indexSourceFile`, this does not parse any source files. This makes it the
+    /// cheaper choice for indexing a translation unit that is already available, such as after a
+    /// call to `clang_reparseTranslationUnit`. `callbacks_size` should be
+    /// `mem::size_of::<IndexerCallbacks>()`.
+    pub fn clang_indexTranslationUnit(action: CXIndexAction, data: CXClientData, callbacks: *mut IndexerCallbacks, callbacks_size: c_uint, flags: CXIndexOptFlags, tu: CXTranslationUnit) -> c_int;
     pub fn clang_index_getCXXClassDeclInfo(info: *const CXIdxDeclInfo) -> *const CXIdxCXXClassDeclInfo;
     pub fn clang_index_getClientContainer(info: *const CXIdxContainerInfo) -> CXIdxClientContainer;
     pub fn clang_index_getClientEntity(info: *const CXIdxEntityInfo) -> CXIdxClientEntity;
@@ -1711,7 +1913,7 @@ link! {
     pub fn clang_Comment_getKind(comment: CXComment) -> CXCommentKind;
     pub fn clang_Comment_getNumChildren(comment: CXComment) -> c_uint;
     pub fn clang_Comment_isWhitespace(comment: CXComment) -> c_uint;
-    pub fn clang_Cursor_getParsedComment(C: CXCursor) -> CXComment;
+    pub fn clang_Cursor_getParsedComment(cursor: CXCursor) -> CXComment;
     pub fn clang_FullComment_getAsHTML(comment: CXComment) -> CXString;
     pub fn clang_FullComment_getAsXML(comment: CXComment) -> CXString;
     pub fn clang_HTMLStartTagComment_isSelfClosing(comment: CXComment) -> c_uint;
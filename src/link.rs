@@ -46,7 +46,7 @@ macro_rules! link {
         }
     );
 
-    ($($(#[cfg($cfg:meta)])* pub fn $name:ident($($pname:ident: $pty:ty), *) $(-> $ret:ty)*;)+) => (
+    ($($(#[doc=$doc:expr])* $(#[cfg($cfg:meta)])* pub fn $name:ident($($pname:ident: $pty:ty), *) $(-> $ret:ty)*;)+) => (
         use std::cell::{RefCell};
         use std::sync::{Mutex};
 
@@ -78,7 +78,7 @@ macro_rules! link {
         lazy_static!(static ref LOADED: Mutex<bool> = Mutex::new(false););
         thread_local!(static LIBRARY: RefCell<Option<SharedLibrary>> = RefCell::new(None));
 
-        $($(#[cfg($cfg)])* pub unsafe fn $name($($pname: $pty), *) $(-> $ret)* {
+        $($(#[doc=$doc])* $(#[cfg($cfg)])* pub unsafe fn $name($($pname: $pty), *) $(-> $ret)* {
             let f = LIBRARY.with(|l| l.borrow().as_ref().map(|l| l.functions.$name));
             (f.expect("a `libclang` shared library was not loaded on this thread"))($($pname), *)
         })+
@@ -138,7 +138,7 @@ macro_rules! link {
 
 #[cfg(not(feature="runtime"))]
 macro_rules! link {
-    ($($(#[cfg($cfg:meta)])* pub fn $name:ident($($pname:ident: $pty:ty), *) $(-> $ret:ty)*;)+) => (
-        extern { $($(#[cfg($cfg)])* pub fn $name($($pname: $pty), *) $(-> $ret)*;)+ }
+    ($($(#[doc=$doc:expr])* $(#[cfg($cfg:meta)])* pub fn $name:ident($($pname:ident: $pty:ty), *) $(-> $ret:ty)*;)+) => (
+        extern { $($(#[doc=$doc])* $(#[cfg($cfg)])* pub fn $name($($pname: $pty), *) $(-> $ret)*;)+ }
     )
 }
@@ -1,12 +1,26 @@
 extern crate clang_sys;
 extern crate libc;
 
+use std::collections::{HashSet};
 use std::ptr;
 
 use clang_sys::*;
 
 use libc::{c_char};
 
+extern "C" fn visit_function(
+    cursor: CXCursor, _: CXCursor, data: CXClientData
+) -> CXChildVisitResult {
+    unsafe {
+        if clang_getCursorKind(cursor) == CXCursor_FunctionDecl {
+            *(data as *mut CXCursor) = cursor;
+            CXChildVisit_Break
+        } else {
+            CXChildVisit_Continue
+        }
+    }
+}
+
 fn parse() {
     unsafe {
         let index = clang_createIndex(0, 0);
@@ -22,6 +36,96 @@ fn parse() {
             CXTranslationUnit_Flags::empty(),
         );
         assert!(!tu.is_null());
+
+        let cursor = clang_getTranslationUnitCursor(tu);
+        let range = clang_getCursorExtent(cursor);
+        assert_eq!(range, clang_getCursorExtent(cursor));
+        assert!(range != clang_getNullRange());
+        assert!(clang_getRangeStart(range) != clang_getNullLocation());
+        assert!(clang_getRangeStart(range) != clang_getRangeEnd(range));
+
+        let mut function = clang_getNullCursor();
+        let data = &mut function as *mut CXCursor as CXClientData;
+        clang_visitChildren(cursor, visit_function, data);
+        assert_eq!(clang_getCursorKind(function), CXCursor_FunctionDecl);
+        assert!(function != cursor);
+        assert!(clang_getCursorExtent(function) != range);
+
+        let mut cursors = HashSet::new();
+        cursors.insert(cursor);
+        cursors.insert(clang_getTranslationUnitCursor(tu));
+        assert_eq!(cursors.len(), 1);
+        assert!(cursors.contains(&clang_getTranslationUnitCursor(tu)));
+        assert!(!cursors.contains(&function));
+
+        let ty = clang_getCursorType(function);
+        assert_eq!(ty.kind, CXType_FunctionProto);
+        assert_eq!(ty, clang_getCursorType(function));
+        assert!(ty != clang_getCursorType(cursor));
+    }
+}
+
+#[cfg(all(feature="gte_clang_3_6", unix))]
+fn file_is_equal() {
+    use std::env;
+    use std::ffi::{CString};
+    use std::fs::{self, File};
+    use std::io::{Write};
+    use std::os::unix::fs::{symlink};
+    use std::path::{Path, PathBuf};
+    use std::process;
+
+    /// A temporary directory that is removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    let directory = TempDir(env::temp_dir().join(format!("clang-sys-{}", process::id())));
+    let _ = fs::remove_dir_all(&directory.0);
+    fs::create_dir(&directory.0).unwrap();
+
+    let header = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/header.h");
+    let link = directory.0.join("link.h");
+    symlink(&header, &link).unwrap();
+    let other = directory.0.join("other.h");
+    File::create(&other).unwrap().write_all(b"int subtract(int a, int b);\n").unwrap();
+
+    unsafe {
+        let index = clang_createIndex(0, 0);
+        assert!(!index.is_null());
+
+        let get_file = |path: &Path| {
+            let path = CString::new(path.to_str().unwrap()).unwrap();
+            let tu = clang_parseTranslationUnit(
+                index,
+                path.as_ptr(),
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                0,
+                CXTranslationUnit_Flags::empty(),
+            );
+            assert!(!tu.is_null());
+            let file = clang_getFile(tu, path.as_ptr());
+            assert!(!file.is_null());
+            (tu, file)
+        };
+
+        let (header_tu, header_file) = get_file(&header);
+        let (link_tu, link_file) = get_file(&link);
+        let (other_tu, other_file) = get_file(&other);
+        assert!(clang_File_isEqual(header_file, link_file) != 0);
+        assert!(clang_File_isEqual(header_file, other_file) == 0);
+        assert!(clang_File_isEqual(link_file, other_file) == 0);
+
+        clang_disposeTranslationUnit(header_tu);
+        clang_disposeTranslationUnit(link_tu);
+        clang_disposeTranslationUnit(other_tu);
+        clang_disposeIndex(index);
     }
 }
 
@@ -30,6 +134,8 @@ fn parse() {
 fn test() {
     load().unwrap();
     parse();
+    #[cfg(all(feature="gte_clang_3_6", unix))]
+    file_is_equal();
     unload().unwrap();
 }
 
@@ -37,6 +143,8 @@ fn test() {
 #[test]
 fn test() {
     parse();
+    #[cfg(all(feature="gte_clang_3_6", unix))]
+    file_is_equal();
 }
 
 #[test]